@@ -1,11 +1,98 @@
 use darling::FromAttributes;
 use proc_macro2::TokenStream as TokenStream2;
 use proc_macro_error::abort;
-use quote::quote;
-use syn::{Data, DataStruct, DeriveInput, FieldsNamed};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{
+    Attribute, Data, DataStruct, DeriveInput, Field, Fields, FieldsNamed, FieldsUnnamed,
+    GenericArgument, Ident, LitStr, PathArguments, Token, Type, Variant, WherePredicate,
+};
 
 #[derive(Debug, Default, FromAttributes)]
 #[darling(attributes(better_debug))]
+struct ContainerOptions {
+    /// Replace the inferred `where` bounds on the generated `Debug` impl with
+    /// these predicates instead. An empty string adds no bounds at all.
+    bound: Option<String>,
+    /// Forward the `Debug` impl straight to the sole field instead of wrapping
+    /// it in a `debug_struct`/`debug_tuple` block. Only valid on types with
+    /// exactly one field.
+    transparent: Option<bool>,
+}
+
+#[derive(Debug, Default, FromAttributes)]
+#[darling(attributes(better_debug))]
+struct VariantOptions {
+    /// Same as [`ContainerOptions::transparent`], but scoped to a single variant.
+    transparent: Option<bool>,
+}
+
+/// Validate that `field` can be used as the sole field of a `transparent` type
+/// or variant: `secret`/`cust_formatter`/`format` wouldn't have anywhere to
+/// apply since the `Debug` impl is forwarded verbatim.
+fn check_transparent_field(field: &Field) -> syn::Result<()> {
+    let field_attributes = parse_field_options(&field.attrs)?;
+    if field_attributes.secret.unwrap_or(false)
+        || field_attributes.cust_formatter.is_some()
+        || field_attributes.format.is_some()
+    {
+        abort!(
+            field,
+            "transparent is mutually exclusive with secret/cust_formatter/format on the sole field"
+        );
+    }
+    Ok(())
+}
+
+/// Build the `core::fmt::Debug::fmt` forwarding body for a `transparent` struct.
+fn expand_transparent_fields(fields: &Fields) -> syn::Result<TokenStream2> {
+    if fields.len() != 1 {
+        abort!(fields, "transparent requires exactly one field");
+    }
+    let field = fields.iter().next().expect("checked len == 1 above");
+    check_transparent_field(field)?;
+    let access = match &field.ident {
+        Some(ident) => quote! { &self.#ident },
+        None => quote! { &self.0 },
+    };
+    Ok(quote! { core::fmt::Debug::fmt(#access, f) })
+}
+
+/// Build the `match` arm that forwards a `transparent` enum variant's `Debug`
+/// impl to its sole field.
+fn expand_transparent_variant(
+    iden: &Ident,
+    variant_ident: &Ident,
+    fields: &Fields,
+) -> syn::Result<TokenStream2> {
+    if fields.len() != 1 {
+        abort!(fields, "transparent requires exactly one field");
+    }
+    let field = fields.iter().next().expect("checked len == 1 above");
+    check_transparent_field(field)?;
+    match fields {
+        Fields::Named(_) => {
+            let field_ident = field
+                .ident
+                .as_ref()
+                .expect("named field always has an ident");
+            Ok(quote! {
+                #iden::#variant_ident { #field_ident } => core::fmt::Debug::fmt(#field_ident, f),
+            })
+        }
+        Fields::Unnamed(_) => {
+            let binding = format_ident!("field_0");
+            Ok(quote! {
+                #iden::#variant_ident(#binding) => core::fmt::Debug::fmt(#binding, f),
+            })
+        }
+        Fields::Unit => unreachable!("Fields::Unit has zero fields, caught by the len check above"),
+    }
+}
+
+#[derive(Default, FromAttributes)]
+#[darling(attributes(better_debug))]
 struct FieldOptions {
     /// Whether this field should be ignored or not.
     ignore: Option<bool>,
@@ -23,17 +110,29 @@ struct FieldOptions {
     /// Whether to skip formatting if the formatter returns None.
     /// Set to false by default.
     cust_formatter_skip_if_none: Option<bool>,
+    /// Inline `format_args!`-style formatter: a literal plus optional
+    /// comma-separated argument expressions, e.g.
+    /// `#[better_debug(format = "{:08b}", self.flags)]`. Parsed by hand in
+    /// [`parse_field_options`] since darling can't parse trailing expressions,
+    /// so it's excluded from darling's own attribute parsing.
+    #[darling(skip)]
+    format: Option<FormatAttr>,
 }
 
 impl FieldOptions {
     /// Whether this FieldOptions is invalid. Generally, if `ignore` is set to
     /// true, then it doesn't make any sense to use any of the other options.
-    fn is_invalid(&self) -> bool {
+    ///
+    /// `positional` should be `true` for tuple struct fields and tuple enum
+    /// variant fields, since `rename_to` has no meaning for a field that isn't
+    /// addressed by name.
+    fn is_invalid(&self, positional: bool) -> bool {
         if self.ignore.unwrap_or(false)
             && (self.rename_to.is_some()
                 || self.secret.unwrap_or(false)
                 || self.cust_formatter.is_some()
-                || self.cust_formatter_skip_if_none.unwrap_or(false))
+                || self.cust_formatter_skip_if_none.unwrap_or(false)
+                || self.format.is_some())
         {
             return true;
         }
@@ -44,86 +143,443 @@ impl FieldOptions {
             return true;
         }
 
-        // Fail if secret was set to true and a custom formatter is being used.
-        if self.secret.unwrap_or(false) && self.cust_formatter.is_some() {
+        // Fail if secret was set to true and a custom formatter or inline format
+        // string is being used.
+        if self.secret.unwrap_or(false) && (self.cust_formatter.is_some() || self.format.is_some())
+        {
+            return true;
+        }
+
+        // Fail if both an inline format string and a custom formatter are used.
+        if self.format.is_some() && self.cust_formatter.is_some() {
+            return true;
+        }
+
+        // Fail if rename_to was used on a positional (tuple) field.
+        if positional && self.rename_to.is_some() {
             return true;
         }
         false
     }
 }
 
-pub(crate) fn expand(ast: DeriveInput) -> syn::Result<TokenStream2> {
-    let iden = &ast.ident;
-    let fields = if let Data::Struct(DataStruct {
-        fields: syn::Fields::Named(FieldsNamed { ref named, .. }),
-        ..
-    }) = ast.data
-    {
-        named
-    } else {
-        abort!(iden, "BetterDebug only works with structs");
-    };
+/// Parsed form of `#[better_debug(format = "...", expr, expr, ...)]`.
+struct FormatAttr {
+    lit: LitStr,
+    args: Vec<syn::Expr>,
+}
 
-    let mut out = vec![];
-    for field in fields {
-        let field_attributes = FieldOptions::from_attributes(&field.attrs)?;
-        if field_attributes.is_invalid() {
-            abort!(
-                field.ident,
-                "Selected options aren't compatible with each other."
-            );
+impl Parse for FormatAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let keyword: Ident = input.parse()?;
+        if keyword != "format" {
+            return Err(syn::Error::new(keyword.span(), "expected `format`"));
+        }
+        input.parse::<Token![=]>()?;
+        let lit: LitStr = input.parse()?;
+        let mut args = vec![];
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            args.push(input.parse::<syn::Expr>()?);
         }
-        if field_attributes.ignore.unwrap_or(false) {
-            continue;
+        Ok(FormatAttr { lit, args })
+    }
+}
+
+/// Parse the `#[better_debug(...)]` attributes on a field into [`FieldOptions`].
+///
+/// A `format = "...", args...` attribute is pulled out and parsed by hand
+/// first, since its trailing argument expressions aren't valid `syn::Meta`
+/// and would otherwise make darling reject the whole attribute.
+fn parse_field_options(attrs: &[Attribute]) -> syn::Result<FieldOptions> {
+    let mut rest = Vec::with_capacity(attrs.len());
+    let mut format = None;
+    for attr in attrs {
+        if attr.path().is_ident("better_debug") {
+            if let Ok(parsed) = attr.parse_args::<FormatAttr>() {
+                if format.is_some() {
+                    abort!(attr, "Only one `format` attribute is allowed per field.");
+                }
+                format = Some(parsed);
+                continue;
+            }
         }
-        let field_ident = match &field.ident {
-            Some(ident) => ident,
-            None => abort!(field, "Field must have an identifier."),
-        };
-        let field_name = match field_attributes.rename_to {
-            Some(name) => name,
-            None => field_ident.to_string(),
-        };
-        if let Some(func) = field_attributes.cust_formatter {
-            let expr = syn::parse_str::<syn::Expr>(&func)?;
-            match field_attributes
-                .cust_formatter_skip_if_none
-                .unwrap_or(false)
+        rest.push(attr.clone());
+    }
+    let mut field_attributes = FieldOptions::from_attributes(&rest)?;
+    field_attributes.format = format;
+    Ok(field_attributes)
+}
+
+/// Build the statement that registers a single named field with `dbg_struct`,
+/// honoring `format`/`secret`/`cust_formatter`/`cust_formatter_skip_if_none`.
+/// `ignore` is not handled here: callers skip ignored fields before calling it.
+///
+/// `access` is the token stream used to read the field's value (e.g. `&self.bar`
+/// for a struct field, or `bar` for a field bound by an enum match arm).
+/// `formatter_arg` is what gets passed to `cust_formatter`.
+fn named_field_stmt(
+    field_attributes: &FieldOptions,
+    field_name: &str,
+    access: TokenStream2,
+    formatter_arg: TokenStream2,
+) -> syn::Result<TokenStream2> {
+    if let Some(FormatAttr { lit, args }) = &field_attributes.format {
+        return Ok(quote! {
+            dbg_struct.field(#field_name, &format_args!(#lit, #(#args),*));
+        });
+    }
+    if let Some(func) = &field_attributes.cust_formatter {
+        let expr = syn::parse_str::<syn::Expr>(func)?;
+        return Ok(match field_attributes
+            .cust_formatter_skip_if_none
+            .unwrap_or(false)
+        {
+            // If custom formatter returned none, skip formatting
+            true => quote! {
+                if let Some(out) = #expr(#formatter_arg){
+                    dbg_struct.field(#field_name, &out);
+                }
+            },
+            // Use default formatter if cust formatter returned None
+            false => quote! {
+                if let Some(out) = #expr(#formatter_arg){
+                    dbg_struct.field(#field_name, &out);
+                } else {
+                    dbg_struct.field(#field_name, #access);
+                }
+            },
+        });
+    }
+    if field_attributes.secret.unwrap_or(false) {
+        return Ok(quote! {
+            dbg_struct.field(#field_name, &"<SECRET>");
+        });
+    }
+    Ok(quote! {
+        dbg_struct.field(#field_name, #access);
+    })
+}
+
+/// Same as [`named_field_stmt`], but registers the field positionally with
+/// `dbg_tuple` instead of by name with `dbg_struct`.
+fn unnamed_field_stmt(
+    field_attributes: &FieldOptions,
+    access: TokenStream2,
+    formatter_arg: TokenStream2,
+) -> syn::Result<TokenStream2> {
+    if let Some(FormatAttr { lit, args }) = &field_attributes.format {
+        return Ok(quote! {
+            dbg_tuple.field(&format_args!(#lit, #(#args),*));
+        });
+    }
+    if let Some(func) = &field_attributes.cust_formatter {
+        let expr = syn::parse_str::<syn::Expr>(func)?;
+        return Ok(match field_attributes
+            .cust_formatter_skip_if_none
+            .unwrap_or(false)
+        {
+            true => quote! {
+                if let Some(out) = #expr(#formatter_arg){
+                    dbg_tuple.field(&out);
+                }
+            },
+            false => quote! {
+                if let Some(out) = #expr(#formatter_arg){
+                    dbg_tuple.field(&out);
+                } else {
+                    dbg_tuple.field(#access);
+                }
+            },
+        });
+    }
+    if field_attributes.secret.unwrap_or(false) {
+        return Ok(quote! {
+            dbg_tuple.field(&"<SECRET>");
+        });
+    }
+    Ok(quote! {
+        dbg_tuple.field(#access);
+    })
+}
+
+/// Build the `match` arm that formats a single enum variant.
+fn expand_variant(iden: &Ident, variant: &Variant) -> syn::Result<TokenStream2> {
+    let variant_ident = &variant.ident;
+    let variant_name = variant_ident.to_string();
+
+    let variant_attributes = VariantOptions::from_attributes(&variant.attrs)?;
+    if variant_attributes.transparent.unwrap_or(false) {
+        return expand_transparent_variant(iden, variant_ident, &variant.fields);
+    }
+
+    match &variant.fields {
+        Fields::Named(FieldsNamed { named, .. }) => {
+            let mut bindings = vec![];
+            let mut out = vec![];
+            for field in named {
+                let field_attributes = parse_field_options(&field.attrs)?;
+                if field_attributes.is_invalid(false) {
+                    abort!(
+                        field.ident,
+                        "Selected options aren't compatible with each other."
+                    );
+                }
+                let field_ident = match &field.ident {
+                    Some(ident) => ident,
+                    None => abort!(field, "Field must have an identifier."),
+                };
+                if field_attributes.ignore.unwrap_or(false) {
+                    bindings.push(quote! { #field_ident: _ });
+                    continue;
+                }
+                bindings.push(quote! { #field_ident });
+                let field_name = field_attributes
+                    .rename_to
+                    .clone()
+                    .unwrap_or_else(|| field_ident.to_string());
+                out.push(named_field_stmt(
+                    &field_attributes,
+                    &field_name,
+                    quote! { #field_ident },
+                    quote! { #field_ident },
+                )?);
+            }
+            Ok(quote! {
+                #iden::#variant_ident { #(#bindings),* } => {
+                    let mut dbg_struct = f.debug_struct(#variant_name);
+                    #(#out)*
+                    dbg_struct.finish()
+                }
+            })
+        }
+        Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => {
+            let mut bindings = vec![];
+            let mut out = vec![];
+            for (index, field) in unnamed.iter().enumerate() {
+                let field_attributes = parse_field_options(&field.attrs)?;
+                if field_attributes.is_invalid(true) {
+                    abort!(
+                        field,
+                        "Selected options aren't compatible with each other."
+                    );
+                }
+                let binding = format_ident!("field_{}", index);
+                bindings.push(quote! { #binding });
+                if field_attributes.ignore.unwrap_or(false) {
+                    continue;
+                }
+                out.push(unnamed_field_stmt(
+                    &field_attributes,
+                    quote! { #binding },
+                    quote! { #binding },
+                )?);
+            }
+            Ok(quote! {
+                #iden::#variant_ident(#(#bindings),*) => {
+                    let mut dbg_tuple = f.debug_tuple(#variant_name);
+                    #(#out)*
+                    dbg_tuple.finish()
+                }
+            })
+        }
+        Fields::Unit => Ok(quote! {
+            #iden::#variant_ident => f.write_str(#variant_name),
+        }),
+    }
+}
+
+/// Collect the types of every field across a struct's fields or an enum's variants.
+fn collect_field_types(data: &Data) -> Vec<&Type> {
+    match data {
+        Data::Struct(DataStruct { fields, .. }) => fields.iter().map(|field| &field.ty).collect(),
+        Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .map(|field| &field.ty)
+            .collect(),
+        Data::Union(_) => vec![],
+    }
+}
+
+/// Whether `ty` mentions `param` anywhere other than inside a `PhantomData<...>`.
+/// `PhantomData<T>: Debug` holds unconditionally, so a type parameter that only
+/// ever shows up there doesn't need a `Debug` bound.
+///
+/// Only path, reference, pointer, array, slice, tuple, paren and group types
+/// are searched; any other type form (e.g. `fn(T)`, `dyn Trait<T>`) counts as
+/// not mentioning `param`.
+fn type_mentions_param(ty: &Type, param: &Ident) -> bool {
+    match ty {
+        Type::Path(type_path) => {
+            if type_path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "PhantomData")
             {
-                // If custom formatter returned none, skip formatting
-                true => out.push(quote! {
-                    if let Some(out) = #expr(&self){
-                        dbg_struct.field(#field_name, &out);
-                    }
-                }),
-                // Use default formatter if cust formatter returned None
-                false => out.push(quote! {
-                    if let Some(out) = #expr(&self){
-                        dbg_struct.field(#field_name, &out);
-                    } else {
-                        dbg_struct.field(#field_name, &self.#field_ident);
+                return false;
+            }
+            type_path.path.segments.iter().any(|segment| {
+                segment.ident == *param
+                    || match &segment.arguments {
+                        PathArguments::AngleBracketed(args) => args.args.iter().any(|arg| {
+                            matches!(arg, GenericArgument::Type(inner) if type_mentions_param(inner, param))
+                        }),
+                        _ => false,
                     }
-                }),
-            }
-        } else if field_attributes.secret.unwrap_or(false) {
-            out.push(quote! {
-                dbg_struct.field(#field_name, &"<SECRET>");
-            });
-        } else {
-            out.push(quote! {
-                dbg_struct.field(#field_name, &self.#field_ident);
-            });
+            })
         }
+        Type::Reference(r) => type_mentions_param(&r.elem, param),
+        Type::Paren(t) => type_mentions_param(&t.elem, param),
+        Type::Group(t) => type_mentions_param(&t.elem, param),
+        Type::Ptr(t) => type_mentions_param(&t.elem, param),
+        Type::Array(t) => type_mentions_param(&t.elem, param),
+        Type::Slice(t) => type_mentions_param(&t.elem, param),
+        Type::Tuple(t) => t.elems.iter().any(|elem| type_mentions_param(elem, param)),
+        _ => false,
     }
+}
 
-    let ident_name = iden.to_string();
+pub(crate) fn expand(ast: DeriveInput) -> syn::Result<TokenStream2> {
+    let iden = &ast.ident;
+    let container_attributes = ContainerOptions::from_attributes(&ast.attrs)?;
+
+    let body = if container_attributes.transparent.unwrap_or(false) {
+        match &ast.data {
+            Data::Struct(DataStruct { fields, .. }) => expand_transparent_fields(fields)?,
+            _ => abort!(iden, "transparent is only supported on structs and enum variants"),
+        }
+    } else {
+        match &ast.data {
+            Data::Struct(DataStruct {
+                fields: Fields::Named(FieldsNamed { named, .. }),
+                ..
+            }) => {
+                let ident_name = iden.to_string();
+                let mut out = vec![];
+                for field in named {
+                    let field_attributes = parse_field_options(&field.attrs)?;
+                    if field_attributes.is_invalid(false) {
+                        abort!(
+                            field.ident,
+                            "Selected options aren't compatible with each other."
+                        );
+                    }
+                    if field_attributes.ignore.unwrap_or(false) {
+                        continue;
+                    }
+                    let field_ident = match &field.ident {
+                        Some(ident) => ident,
+                        None => abort!(field, "Field must have an identifier."),
+                    };
+                    let field_name = field_attributes
+                        .rename_to
+                        .clone()
+                        .unwrap_or_else(|| field_ident.to_string());
+                    out.push(named_field_stmt(
+                        &field_attributes,
+                        &field_name,
+                        quote! { &self.#field_ident },
+                        quote! { &self },
+                    )?);
+                }
+                quote! {
+                    let mut dbg_struct = f.debug_struct(#ident_name);
+                    #(#out)*
+                    dbg_struct.finish()
+                }
+            }
+            Data::Struct(DataStruct {
+                fields: Fields::Unnamed(FieldsUnnamed { unnamed, .. }),
+                ..
+            }) => {
+                let ident_name = iden.to_string();
+                let mut out = vec![];
+                for (index, field) in unnamed.iter().enumerate() {
+                    let field_attributes = parse_field_options(&field.attrs)?;
+                    if field_attributes.is_invalid(true) {
+                        abort!(
+                            field,
+                            "Selected options aren't compatible with each other."
+                        );
+                    }
+                    if field_attributes.ignore.unwrap_or(false) {
+                        continue;
+                    }
+                    let index = syn::Index::from(index);
+                    out.push(unnamed_field_stmt(
+                        &field_attributes,
+                        quote! { &self.#index },
+                        quote! { &self },
+                    )?);
+                }
+                quote! {
+                    let mut dbg_tuple = f.debug_tuple(#ident_name);
+                    #(#out)*
+                    dbg_tuple.finish()
+                }
+            }
+            Data::Struct(DataStruct {
+                fields: Fields::Unit,
+                ..
+            }) => {
+                let ident_name = iden.to_string();
+                quote! { f.write_str(#ident_name) }
+            }
+            Data::Enum(data_enum) => {
+                let arms = data_enum
+                    .variants
+                    .iter()
+                    .map(|variant| expand_variant(iden, variant))
+                    .collect::<syn::Result<Vec<_>>>()?;
+                quote! {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            }
+            _ => abort!(iden, "BetterDebug only works with structs and enums"),
+        }
+    };
+
+    let mut generics = ast.generics.clone();
+    match &container_attributes.bound {
+        Some(bound) if bound.is_empty() => {}
+        Some(bound) => {
+            let predicates = syn::parse::Parser::parse_str(
+                Punctuated::<WherePredicate, Token![,]>::parse_terminated,
+                bound,
+            )?;
+            generics.make_where_clause().predicates.extend(predicates);
+        }
+        None => {
+            let field_types = collect_field_types(&ast.data);
+            let param_idents: Vec<Ident> =
+                generics.type_params().map(|param| param.ident.clone()).collect();
+            for param_ident in param_idents {
+                if field_types
+                    .iter()
+                    .any(|ty| type_mentions_param(ty, &param_ident))
+                {
+                    generics
+                        .make_where_clause()
+                        .predicates
+                        .push(syn::parse_quote! { #param_ident: core::fmt::Debug });
+                }
+            }
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let expanded = quote! {
-        impl core::fmt::Debug for #iden {
+        impl #impl_generics core::fmt::Debug for #iden #ty_generics #where_clause {
             fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-                let mut dbg_struct = f.debug_struct(#ident_name);
-                #(#out)*
-                dbg_struct.finish()
+                #body
             }
         }
     };
@@ -376,4 +832,451 @@ mod tests {
         };
         expand(input).unwrap();
     }
+
+    #[test]
+    fn test_expand_enum_unit_variants() {
+        let input = parse_quote! {
+            #[derive(BetterDebug)]
+            enum Foo {
+                Bar,
+                Baz,
+            }
+        };
+        let expected = quote! {
+            impl core::fmt::Debug for Foo {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    match self {
+                        Foo::Bar => f.write_str("Bar"),
+                        Foo::Baz => f.write_str("Baz"),
+                    }
+                }
+            }
+        };
+        let expanded = expand(input).unwrap();
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_expand_enum_tuple_variant() {
+        let input = parse_quote! {
+            #[derive(BetterDebug)]
+            enum Foo {
+                Bar(String, #[better_debug(secret)] String),
+            }
+        };
+        let expected = quote! {
+            impl core::fmt::Debug for Foo {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    match self {
+                        Foo::Bar(field_0, field_1) => {
+                            let mut dbg_tuple = f.debug_tuple("Bar");
+                            dbg_tuple.field(field_0);
+                            dbg_tuple.field(&"<SECRET>");
+                            dbg_tuple.finish()
+                        }
+                    }
+                }
+            }
+        };
+        let expanded = expand(input).unwrap();
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_expand_enum_struct_variant() {
+        let input = parse_quote! {
+            #[derive(BetterDebug)]
+            enum Foo {
+                Bar {
+                    #[better_debug(rename_to = "new_name")]
+                    bar: String,
+                    #[better_debug(ignore)]
+                    baz: String,
+                    qux: String,
+                },
+            }
+        };
+        let expected = quote! {
+            impl core::fmt::Debug for Foo {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    match self {
+                        Foo::Bar { bar, baz: _, qux } => {
+                            let mut dbg_struct = f.debug_struct("Bar");
+                            dbg_struct.field("new_name", bar);
+                            dbg_struct.field("qux", qux);
+                            dbg_struct.finish()
+                        }
+                    }
+                }
+            }
+        };
+        let expanded = expand(input).unwrap();
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_expand_enum_cust_formatter_receives_binding() {
+        let input = parse_quote! {
+            #[derive(BetterDebug)]
+            enum Foo {
+                Bar {
+                    #[better_debug(cust_formatter = "foo")]
+                    bar: String,
+                },
+            }
+        };
+        let expected = quote! {
+            impl core::fmt::Debug for Foo {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    match self {
+                        Foo::Bar { bar } => {
+                            let mut dbg_struct = f.debug_struct("Bar");
+                            if let Some(out) = foo(bar){
+                                dbg_struct.field("bar", &out);
+                            } else {
+                                dbg_struct.field("bar", bar);
+                            }
+                            dbg_struct.finish()
+                        }
+                    }
+                }
+            }
+        };
+        let expanded = expand(input).unwrap();
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_expand_tuple_struct() {
+        let input = parse_quote! {
+            #[derive(BetterDebug)]
+            struct Foo(String, #[better_debug(secret)] String);
+        };
+        let expected = quote! {
+            impl core::fmt::Debug for Foo {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    let mut dbg_tuple = f.debug_tuple("Foo");
+                    dbg_tuple.field(&self.0);
+                    dbg_tuple.field(&"<SECRET>");
+                    dbg_tuple.finish()
+                }
+            }
+        };
+        let expanded = expand(input).unwrap();
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_expand_unit_struct() {
+        let input = parse_quote! {
+            #[derive(BetterDebug)]
+            struct Foo;
+        };
+        let expected = quote! {
+            impl core::fmt::Debug for Foo {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    f.write_str("Foo")
+                }
+            }
+        };
+        let expanded = expand(input).unwrap();
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_tuple_struct_rename_to() {
+        let input = parse_quote! {
+            #[derive(BetterDebug)]
+            struct Foo(#[better_debug(rename_to = "bar")] String);
+        };
+        expand(input).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_enum_tuple_variant_rename_to() {
+        let input = parse_quote! {
+            #[derive(BetterDebug)]
+            enum Foo {
+                Bar(#[better_debug(rename_to = "x")] String),
+            }
+        };
+        expand(input).unwrap();
+    }
+
+    #[test]
+    fn test_expand_generic_struct() {
+        let input = parse_quote! {
+            #[derive(BetterDebug)]
+            struct Foo<T> {
+                bar: T,
+            }
+        };
+        let expected = quote! {
+            impl<T> core::fmt::Debug for Foo<T> where T: core::fmt::Debug {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    let mut dbg_struct = f.debug_struct("Foo");
+                    dbg_struct.field("bar", &self.bar);
+                    dbg_struct.finish()
+                }
+            }
+        };
+        let expanded = expand(input).unwrap();
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_expand_phantom_data_skips_bound() {
+        let input = parse_quote! {
+            #[derive(BetterDebug)]
+            struct Foo<T> {
+                bar: core::marker::PhantomData<T>,
+            }
+        };
+        let expected = quote! {
+            impl<T> core::fmt::Debug for Foo<T> {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    let mut dbg_struct = f.debug_struct("Foo");
+                    dbg_struct.field("bar", &self.bar);
+                    dbg_struct.finish()
+                }
+            }
+        };
+        let expanded = expand(input).unwrap();
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_expand_bound_override() {
+        let input = parse_quote! {
+            #[better_debug(bound = "T: Clone")]
+            #[derive(BetterDebug)]
+            struct Foo<T> {
+                #[better_debug(secret)]
+                bar: T,
+            }
+        };
+        let expected = quote! {
+            impl<T> core::fmt::Debug for Foo<T> where T: Clone {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    let mut dbg_struct = f.debug_struct("Foo");
+                    dbg_struct.field("bar", &"<SECRET>");
+                    dbg_struct.finish()
+                }
+            }
+        };
+        let expanded = expand(input).unwrap();
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_expand_bound_override_empty() {
+        let input = parse_quote! {
+            #[better_debug(bound = "")]
+            #[derive(BetterDebug)]
+            struct Foo<T> {
+                bar: T,
+            }
+        };
+        let expected = quote! {
+            impl<T> core::fmt::Debug for Foo<T> {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    let mut dbg_struct = f.debug_struct("Foo");
+                    dbg_struct.field("bar", &self.bar);
+                    dbg_struct.finish()
+                }
+            }
+        };
+        let expanded = expand(input).unwrap();
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_expand_transparent_tuple_struct() {
+        let input = parse_quote! {
+            #[better_debug(transparent)]
+            #[derive(BetterDebug)]
+            struct Foo(isize);
+        };
+        let expected = quote! {
+            impl core::fmt::Debug for Foo {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    core::fmt::Debug::fmt(&self.0, f)
+                }
+            }
+        };
+        let expanded = expand(input).unwrap();
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_expand_transparent_named_struct() {
+        let input = parse_quote! {
+            #[better_debug(transparent)]
+            #[derive(BetterDebug)]
+            struct Foo {
+                bar: isize,
+            }
+        };
+        let expected = quote! {
+            impl core::fmt::Debug for Foo {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    core::fmt::Debug::fmt(&self.bar, f)
+                }
+            }
+        };
+        let expanded = expand(input).unwrap();
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_expand_transparent_variant() {
+        let input = parse_quote! {
+            #[derive(BetterDebug)]
+            enum Foo {
+                #[better_debug(transparent)]
+                Bar(isize),
+                Baz,
+            }
+        };
+        let expected = quote! {
+            impl core::fmt::Debug for Foo {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    match self {
+                        Foo::Bar(field_0) => core::fmt::Debug::fmt(field_0, f),
+                        Foo::Baz => f.write_str("Baz"),
+                    }
+                }
+            }
+        };
+        let expanded = expand(input).unwrap();
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_transparent_multiple_fields() {
+        let input = parse_quote! {
+            #[better_debug(transparent)]
+            #[derive(BetterDebug)]
+            struct Foo(isize, isize);
+        };
+        expand(input).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_transparent_secret() {
+        let input = parse_quote! {
+            #[better_debug(transparent)]
+            #[derive(BetterDebug)]
+            struct Foo(#[better_debug(secret)] isize);
+        };
+        expand(input).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_transparent_format() {
+        let input = parse_quote! {
+            #[better_debug(transparent)]
+            #[derive(BetterDebug)]
+            struct Foo(#[better_debug(format = "{:08b}", self.0)] u8);
+        };
+        expand(input).unwrap();
+    }
+
+    #[test]
+    fn test_expand_format() {
+        let input = parse_quote! {
+            #[derive(BetterDebug)]
+            struct Foo {
+                #[better_debug(format = "{:08b}", self.flags)]
+                flags: u8,
+                baz: String,
+            }
+        };
+        let expected = quote! {
+            impl core::fmt::Debug for Foo {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    let mut dbg_struct = f.debug_struct("Foo");
+                    dbg_struct.field("flags", &format_args!("{:08b}", self.flags));
+                    dbg_struct.field("baz", &self.baz);
+                    dbg_struct.finish()
+                }
+            }
+        };
+        let expanded = expand(input).unwrap();
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_expand_format_method_call_arg() {
+        let input = parse_quote! {
+            #[derive(BetterDebug)]
+            struct Foo {
+                #[better_debug(format = "{} bytes", self.buf.len())]
+                buf: Vec<u8>,
+            }
+        };
+        let expected = quote! {
+            impl core::fmt::Debug for Foo {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    let mut dbg_struct = f.debug_struct("Foo");
+                    dbg_struct.field("buf", &format_args!("{} bytes", self.buf.len()));
+                    dbg_struct.finish()
+                }
+            }
+        };
+        let expanded = expand(input).unwrap();
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_expand_format_tuple_struct() {
+        let input = parse_quote! {
+            #[derive(BetterDebug)]
+            struct Foo(#[better_debug(format = "{:#x}", self.0)] u32);
+        };
+        let expected = quote! {
+            impl core::fmt::Debug for Foo {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    let mut dbg_tuple = f.debug_tuple("Foo");
+                    dbg_tuple.field(&format_args!("{:#x}", self.0));
+                    dbg_tuple.finish()
+                }
+            }
+        };
+        let expanded = expand(input).unwrap();
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_format_secret() {
+        let input = parse_quote! {
+            #[derive(BetterDebug)]
+            struct Foo {
+                #[better_debug(format = "{:08b}", self.flags)]
+                #[better_debug(secret)]
+                flags: u8,
+            }
+        };
+        expand(input).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_format_cust_formatter() {
+        let input = parse_quote! {
+            #[derive(BetterDebug)]
+            struct Foo {
+                #[better_debug(format = "{:08b}", self.flags)]
+                #[better_debug(cust_formatter = "foo")]
+                flags: u8,
+            }
+        };
+        expand(input).unwrap();
+    }
 }
@@ -151,6 +151,65 @@ mod inner;
 ///     baz: String,
 ///}
 /// ```
+///
+/// ## Generic structs
+///
+/// `BetterDebug` adds a `T: core::fmt::Debug` bound for every type parameter
+/// that's actually used by a field, so generic structs just work. Type
+/// parameters that only appear inside `PhantomData<...>` are skipped, since
+/// `PhantomData<T>` implements `Debug` regardless of `T`.
+///
+/// If you need full control over the generated bounds, use the container
+/// attribute `#[better_debug(bound = "...")]`. This replaces the inferred
+/// bounds entirely; pass an empty string to emit no bounds at all.
+///
+/// ```rust
+/// use better_debug::BetterDebug;
+///
+/// // `bar` is printed as `<SECRET>` regardless of `T`, so the inferred
+/// // `T: core::fmt::Debug` bound would be unnecessarily strict here.
+/// #[derive(BetterDebug)]
+/// #[better_debug(bound = "T: Clone")]
+/// struct Foo<T> {
+///     #[better_debug(secret)]
+///     bar: T,
+///}
+/// ```
+///
+/// ## Transparent mode
+///
+/// For a newtype-style struct with exactly one field, `transparent` forwards
+/// the `Debug` impl straight to that field instead of wrapping it in a
+/// `debug_struct`/`debug_tuple` block. `format!("{:?}", Foo(42))` below yields
+/// `42`, not `Foo(42)`.
+///
+/// ```rust
+/// use better_debug::BetterDebug;
+///
+/// #[derive(BetterDebug)]
+/// #[better_debug(transparent)]
+/// struct Foo(isize);
+/// ```
+///
+/// The same attribute works on a single-field enum variant once the enum
+/// itself derives `BetterDebug`.
+///
+/// ## Inline format string
+///
+/// For one-off formatting, `format` is more ergonomic than writing a whole
+/// `cust_formatter` function. It takes a `format_args!`-style literal plus
+/// optional comma-separated argument expressions; `self` is in scope, so the
+/// arguments can read any field of the struct.
+///
+/// ```rust
+/// use better_debug::BetterDebug;
+///
+/// #[derive(BetterDebug)]
+/// struct Foo {
+///     #[better_debug(format = "{:08b}", self.flags)]
+///     flags: u8,
+///}
+/// ```
 #[proc_macro_derive(BetterDebug, attributes(better_debug))]
 #[proc_macro_error]
 pub fn derive(input: TokenStream) -> proc_macro::TokenStream {